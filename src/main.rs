@@ -1,23 +1,224 @@
+use anyhow::Context;
 use anyhow::Result;
 use anyhow::anyhow;
-use std::{collections::HashMap, str::Chars};
+use std::{borrow::Cow, collections::HashMap, fmt, str::Chars};
 
 #[derive(Debug, PartialEq)]
 enum Json {
     String(String),
     Number(f64),
     // not sure if this is real
-    Integer(usize),
+    Integer(i64),
     Array(Vec<Json>),
     Object(HashMap<String, Json>),
     Boolean(bool),
     Null,
 }
 
+/// Zero-copy counterpart to `Json`: strings borrow directly from the input
+/// they were parsed from and only fall back to an owned `String` when an
+/// escape sequence forces decoding. Built via `Parser::parse_borrowed`.
+///
+/// `Array`/`Object` still allocate (`Vec`/`HashMap`), so they're gated behind
+/// the `std` feature (on by default); with it off, only the scalar variants
+/// exist and `parse_borrowed` rejects arrays and objects. The crate itself
+/// still uses `std` either way (`Json`, `Parser` and the error type depend on
+/// it), so turning the feature off does not make it build under `no_std`.
+#[derive(Debug, PartialEq)]
+enum JsonRef<'a> {
+    Str(Cow<'a, str>),
+    Number(f64),
+    Integer(i64),
+    #[cfg(feature = "std")]
+    Array(Vec<JsonRef<'a>>),
+    #[cfg(feature = "std")]
+    Object(HashMap<Cow<'a, str>, JsonRef<'a>>),
+    Boolean(bool),
+    Null,
+}
+
+impl Json {
+    // Compact encoding. Object keys are sorted so the output is
+    // deterministic, since `Object` is backed by a `HashMap`.
+    fn encode(&self, out: &mut String) {
+        match self {
+            Json::String(s) => encode_string(s, out),
+            Json::Number(n) => out.push_str(&format_number(*n)),
+            Json::Integer(i) => out.push_str(&i.to_string()),
+            Json::Boolean(b) => out.push_str(if *b { "true" } else { "false" }),
+            Json::Null => out.push_str("null"),
+            Json::Array(values) => {
+                out.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    value.encode(out);
+                }
+                out.push(']');
+            }
+            Json::Object(map) => {
+                out.push('{');
+                for (i, key) in sorted_keys(map).into_iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    encode_string(key, out);
+                    out.push(':');
+                    map[key].encode(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+
+    fn encode_pretty(&self, out: &mut String, indent: usize, depth: usize) {
+        match self {
+            Json::Array(values) if values.is_empty() => out.push_str("[]"),
+            Json::Array(values) => {
+                out.push_str("[\n");
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(",\n");
+                    }
+                    push_indent(out, indent, depth + 1);
+                    value.encode_pretty(out, indent, depth + 1);
+                }
+                out.push('\n');
+                push_indent(out, indent, depth);
+                out.push(']');
+            }
+            Json::Object(map) if map.is_empty() => out.push_str("{}"),
+            Json::Object(map) => {
+                out.push_str("{\n");
+                for (i, key) in sorted_keys(map).into_iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(",\n");
+                    }
+                    push_indent(out, indent, depth + 1);
+                    encode_string(key, out);
+                    out.push_str(": ");
+                    map[key].encode_pretty(out, indent, depth + 1);
+                }
+                out.push('\n');
+                push_indent(out, indent, depth);
+                out.push('}');
+            }
+            _ => self.encode(out),
+        }
+    }
+
+    /// Pretty-prints with `indent` spaces per nesting level, one element per line.
+    pub fn to_pretty_string(&self, indent: usize) -> String {
+        let mut out = String::new();
+        self.encode_pretty(&mut out, indent, 0);
+        out
+    }
+
+    /// Looks up `key` if this is an `Object`; `None` otherwise, including
+    /// when the key is absent.
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<Json>> {
+        match self {
+            Json::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Widens `Integer` to `f64` alongside `Number`, so callers don't have
+    /// to care which one the parser produced for a given literal.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Number(n) => Some(*n),
+            Json::Integer(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            Json::String(_) => "string",
+            Json::Number(_) => "number",
+            Json::Integer(_) => "integer",
+            Json::Array(_) => "array",
+            Json::Object(_) => "object",
+            Json::Boolean(_) => "boolean",
+            Json::Null => "null",
+        }
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        self.encode(&mut out);
+        f.write_str(&out)
+    }
+}
+
+fn sorted_keys(map: &HashMap<String, Json>) -> Vec<&String> {
+    let mut keys: Vec<&String> = map.keys().collect();
+    keys.sort();
+    keys
+}
+
+fn push_indent(out: &mut String, indent: usize, depth: usize) {
+    for _ in 0..(indent * depth) {
+        out.push(' ');
+    }
+}
+
+// `f64::to_string` drops the fraction for integral values (`2.0` -> `"2"`),
+// which would reparse as `Json::Integer` and flip the variant on round-trip.
+// Append `.0` whenever the formatted value has neither a `.` nor an `e` to
+// keep it unambiguously a `Number`.
+fn format_number(n: f64) -> String {
+    let formatted = n.to_string();
+    if formatted.contains('.') || formatted.contains('e') {
+        formatted
+    } else {
+        formatted + ".0"
+    }
+}
+
+// Inverse of `Parser::parse_string`'s escape handling: quote, backslash and
+// control characters are escaped back into valid JSON.
+fn encode_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{0008}' => out.push_str("\\b"),
+            '\u{000C}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
 struct Parser<'a> {
     current_char: Option<char>,
     iterator: Chars<'a>,
     buffer: String,
+    input: &'a str,
 }
 
 impl<'a> Parser<'a> {
@@ -30,6 +231,7 @@ impl<'a> Parser<'a> {
             buffer,
             current_char,
             iterator,
+            input,
         }
     }
 
@@ -62,6 +264,67 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn parse_hex4(&mut self) -> Result<u16> {
+        let mut value: u16 = 0;
+        for _ in 0..4 {
+            self.advance();
+            let c = self
+                .current_char
+                .ok_or_else(|| anyhow!("Json string never ends!"))?;
+            let digit = c
+                .to_digit(16)
+                .ok_or_else(|| anyhow!("invalid \\u escape, expected hex digit, got: {}", c))?;
+            value = value * 16 + digit as u16;
+        }
+        Ok(value)
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<char> {
+        let high = self.parse_hex4()?;
+
+        if !(0xD800..=0xDBFF).contains(&high) {
+            if (0xDC00..=0xDFFF).contains(&high) {
+                return Err(anyhow!("unpaired low surrogate in \\u escape"));
+            }
+            return char::from_u32(high as u32)
+                .ok_or_else(|| anyhow!("invalid unicode escape: \\u{:04x}", high));
+        }
+
+        self.advance();
+        if self.current_char != Some('\\') {
+            return Err(anyhow!("unpaired high surrogate in \\u escape"));
+        }
+        self.advance();
+        if self.current_char != Some('u') {
+            return Err(anyhow!("unpaired high surrogate in \\u escape"));
+        }
+        let low = self.parse_hex4()?;
+
+        if !(0xDC00..=0xDFFF).contains(&low) {
+            return Err(anyhow!("unpaired high surrogate in \\u escape"));
+        }
+
+        let code_point = 0x10000 + ((high as u32 - 0xD800) << 10) + (low as u32 - 0xDC00);
+        char::from_u32(code_point).ok_or_else(|| anyhow!("invalid surrogate pair"))
+    }
+
+    fn parse_escape(&mut self) -> Result<char> {
+        self.advance();
+        match self.current_char {
+            Some('"') => Ok('"'),
+            Some('\\') => Ok('\\'),
+            Some('/') => Ok('/'),
+            Some('b') => Ok('\u{0008}'),
+            Some('f') => Ok('\u{000C}'),
+            Some('n') => Ok('\n'),
+            Some('r') => Ok('\r'),
+            Some('t') => Ok('\t'),
+            Some('u') => self.parse_unicode_escape(),
+            Some(c) => Err(anyhow!("invalid escape sequence: \\{}", c)),
+            None => Err(anyhow!("Json string never ends!")),
+        }
+    }
+
     fn parse_string(&mut self) -> Result<String> {
         self.advance();
         self.buffer.clear();
@@ -70,6 +333,15 @@ impl<'a> Parser<'a> {
             if c == '"' {
                 return Ok(self.buffer.clone());
             }
+            if c == '\\' {
+                let decoded = self.parse_escape()?;
+                self.buffer.push(decoded);
+                self.advance();
+                continue;
+            }
+            if c < '\u{0020}' {
+                return Err(anyhow!("control character in json string: {:?}", c));
+            }
             self.buffer.push(c);
             self.advance();
         }
@@ -77,7 +349,159 @@ impl<'a> Parser<'a> {
         Err(anyhow!("Json string never ends!"))
     }
 
-    fn parse_object(&mut self) -> Result<Json> {
+    // Byte offset of `current_char` within `input`, used to slice out
+    // borrowed substrings for `parse_borrowed`.
+    fn current_byte_offset(&self) -> usize {
+        let consumed_len = self.current_char.map_or(0, char::len_utf8);
+        self.input.len() - self.iterator.as_str().len() - consumed_len
+    }
+
+    // Zero-copy counterpart to `parse_string`: slices directly into `input`
+    // when the string contains no escapes, and only allocates an owned
+    // `String` once an escape forces decoding.
+    fn parse_string_borrowed(&mut self) -> Result<Cow<'a, str>> {
+        self.advance();
+        let start = self.current_byte_offset();
+        let mut owned: Option<String> = None;
+
+        loop {
+            match self.current_char {
+                Some('"') => break,
+                Some('\\') => {
+                    if owned.is_none() {
+                        let prefix_end = self.current_byte_offset();
+                        owned = Some(self.input[start..prefix_end].to_string());
+                    }
+                    let decoded = self.parse_escape()?;
+                    owned.as_mut().unwrap().push(decoded);
+                    self.advance();
+                }
+                Some(c) if c < '\u{0020}' => {
+                    return Err(anyhow!("control character in json string: {:?}", c));
+                }
+                Some(c) => {
+                    if let Some(buf) = owned.as_mut() {
+                        buf.push(c);
+                    }
+                    self.advance();
+                }
+                None => return Err(anyhow!("Json string never ends!")),
+            }
+        }
+
+        match owned {
+            Some(s) => Ok(Cow::Owned(s)),
+            None => {
+                let end = self.current_byte_offset();
+                Ok(Cow::Borrowed(&self.input[start..end]))
+            }
+        }
+    }
+
+    // Peeks one character past `current_char` without consuming it.
+    fn peek_char(&self) -> Option<char> {
+        self.iterator.clone().next()
+    }
+
+    // Consumes a run of one or more ascii digits starting at `current_char`,
+    // leaving `current_char` on the last digit of the run (mirrors the rest
+    // of the parser, which never advances past the last char of a value).
+    fn eat_digit_run(&mut self) -> Result<()> {
+        let mut count = 0;
+        loop {
+            match self.current_char {
+                Some(c) if c.is_ascii_digit() => {
+                    self.buffer.push(c);
+                    count += 1;
+                }
+                _ => break,
+            }
+            match self.peek_char() {
+                Some(c) if c.is_ascii_digit() => self.advance(),
+                _ => break,
+            }
+        }
+
+        if count == 0 {
+            return Err(anyhow!("invalid json number, expected a digit"));
+        }
+
+        Ok(())
+    }
+
+    fn parse_digits(&mut self) -> Result<Json> {
+        self.buffer.clear();
+        let mut seen_fraction = false;
+        let mut seen_exponent = false;
+
+        if self.current_char == Some('-') {
+            self.buffer.push('-');
+            match self.peek_char() {
+                Some(c) if c.is_ascii_digit() => self.advance(),
+                _ => return Err(anyhow!("invalid json number, expected a digit after '-'")),
+            }
+        }
+
+        match self.current_char {
+            Some('0') => {
+                self.buffer.push('0');
+                if matches!(self.peek_char(), Some(c) if c.is_ascii_digit()) {
+                    return Err(anyhow!("invalid json number, leading zero not allowed"));
+                }
+            }
+            Some(c) if c.is_ascii_digit() => self.eat_digit_run()?,
+            _ => return Err(anyhow!("invalid json number, expected a digit")),
+        }
+
+        if self.peek_char() == Some('.') {
+            seen_fraction = true;
+            self.advance();
+            self.buffer.push('.');
+            self.advance();
+            self.eat_digit_run()
+                .map_err(|_| anyhow!("invalid json number, expected digits after '.'"))?;
+        }
+
+        if matches!(self.peek_char(), Some('e') | Some('E')) {
+            seen_exponent = true;
+            self.advance();
+            self.buffer.push(self.current_char.unwrap());
+
+            if matches!(self.peek_char(), Some('+') | Some('-')) {
+                self.advance();
+                self.buffer.push(self.current_char.unwrap());
+            }
+
+            match self.peek_char() {
+                Some(c) if c.is_ascii_digit() => self.advance(),
+                _ => return Err(anyhow!("invalid json number, expected digits after exponent")),
+            }
+            self.eat_digit_run()?;
+        }
+
+        if seen_fraction || seen_exponent {
+            let value = self.buffer.parse()?;
+            Ok(Json::Number(value))
+        } else {
+            let value = self.buffer.parse()?;
+            Ok(Json::Integer(value))
+        }
+    }
+
+    pub fn parse(&mut self) -> Result<Json> {
+        let mut events = self.events();
+        Json::from_events(&mut events)
+    }
+
+    /// Streams the same document as `parse` token-by-token instead of
+    /// building a `Json` tree, so large inputs can be scanned in O(depth)
+    /// memory.
+    pub fn events(&mut self) -> StreamingParser<'_, 'a> {
+        StreamingParser::new(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn parse_object_borrowed(&mut self) -> Result<JsonRef<'a>> {
         let mut result = HashMap::new();
         loop {
             self.advance();
@@ -89,7 +513,7 @@ impl<'a> Parser<'a> {
                 None => return Err(anyhow!("Invalid Json object composition")),
             }
 
-            let key = self.parse_string()?;
+            let key = self.parse_string_borrowed()?;
 
             self.advance();
             self.eat_whitespace();
@@ -100,7 +524,7 @@ impl<'a> Parser<'a> {
                 None => return Err(anyhow!("Invalid Json object composition")),
             }
 
-            let value = self.parse_value()?;
+            let value = self.parse_value_borrowed()?;
 
             result.insert(key, value);
 
@@ -115,14 +539,15 @@ impl<'a> Parser<'a> {
             }
         }
 
-        Ok(Json::Object(result))
+        Ok(JsonRef::Object(result))
     }
 
-    fn parse_array(&mut self) -> Result<Json> {
+    #[cfg(feature = "std")]
+    fn parse_array_borrowed(&mut self) -> Result<JsonRef<'a>> {
         let mut result = Vec::new();
 
         loop {
-            let value = self.parse_value()?;
+            let value = self.parse_value_borrowed()?;
             result.push(value);
 
             self.advance();
@@ -136,68 +561,454 @@ impl<'a> Parser<'a> {
             }
         }
 
-        Ok(Json::Array(result))
+        Ok(JsonRef::Array(result))
     }
 
-    fn parse_digits(&mut self) -> Result<Json> {
-        let iter_clone = self.iterator.clone();
-        let mut seen_dot = false;
-        self.buffer.clear();
+    fn parse_value_borrowed(&mut self) -> Result<JsonRef<'a>> {
+        self.advance();
+        self.eat_whitespace();
 
-        self.buffer.push(self.current_char.unwrap());
+        match self.current_char {
+            #[cfg(feature = "std")]
+            Some('[') => self.parse_array_borrowed(),
+            #[cfg(feature = "std")]
+            Some('{') => self.parse_object_borrowed(),
+            #[cfg(not(feature = "std"))]
+            Some('[') | Some('{') => Err(anyhow!(
+                "arrays/objects require the `std` feature of this crate"
+            )),
+            Some('"') => Ok(JsonRef::Str(self.parse_string_borrowed()?)),
+            Some('n') => {
+                self.parse_expected_value("ull", Json::Null)?;
+                Ok(JsonRef::Null)
+            }
+            Some('f') => {
+                self.parse_expected_value("alse", Json::Boolean(false))?;
+                Ok(JsonRef::Boolean(false))
+            }
+            Some('t') => {
+                self.parse_expected_value("rue", Json::Boolean(true))?;
+                Ok(JsonRef::Boolean(true))
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' => match self.parse_digits()? {
+                Json::Integer(i) => Ok(JsonRef::Integer(i)),
+                Json::Number(n) => Ok(JsonRef::Number(n)),
+                _ => unreachable!("parse_digits only ever returns Integer or Number"),
+            },
+            Some(c) => Err(anyhow!("Unexpected: {} in json value", c)),
+            None => Err(anyhow!("Invalid json format.")),
+        }
+    }
 
-        for c in iter_clone {
-            if c == '.' && !seen_dot {
-                seen_dot = true;
-            } else if !c.is_ascii_digit() {
-                break;
+    /// Zero-copy counterpart to `parse`: returns a `JsonRef` borrowing from
+    /// this parser's input instead of an owned `Json`.
+    pub fn parse_borrowed(&mut self) -> Result<JsonRef<'a>> {
+        let result = self.parse_value_borrowed()?;
+        self.advance();
+        self.eat_whitespace();
+        match self.current_char {
+            Some(_) => Err(anyhow!("Invalid json format.")),
+            None => Ok(result),
+        }
+    }
+}
+
+/// One step on the path from the document root to the value an event
+/// belongs to: which array index or object key it's nested under.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StackElement {
+    Index(usize),
+    Key(String),
+}
+
+/// A JSON number as reported by the streaming parser, preserving the same
+/// integer/float distinction `Json` makes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonNumber {
+    Integer(i64),
+    Float(f64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonEvent {
+    ObjectStart,
+    ObjectEnd,
+    ArrayStart,
+    ArrayEnd,
+    Key(String),
+    StringVal(String),
+    NumberVal(JsonNumber),
+    BoolVal(bool),
+    NullVal,
+}
+
+enum Frame {
+    Array,
+    Object,
+}
+
+enum Pending {
+    ValueExpected,
+    KeyExpected,
+    AfterArrayElement,
+    AfterObjectValue,
+    CheckTrailing,
+    Done,
+}
+
+/// Event-based adaptor over `Parser` that never builds a `Json` tree: it
+/// drives the same `advance`/`eat_whitespace` primitives as the recursive
+/// parser but emits one `JsonEvent` per step, each paired with the current
+/// `StackElement` path.
+pub struct StreamingParser<'p, 'a> {
+    parser: &'p mut Parser<'a>,
+    stack: Vec<Frame>,
+    path: Vec<StackElement>,
+    pending: Pending,
+}
+
+impl<'p, 'a> StreamingParser<'p, 'a> {
+    fn new(parser: &'p mut Parser<'a>) -> Self {
+        Self {
+            parser,
+            stack: Vec::new(),
+            path: Vec::new(),
+            pending: Pending::ValueExpected,
+        }
+    }
+
+    fn pending_after_value(&self) -> Pending {
+        match self.stack.last() {
+            Some(Frame::Array) => Pending::AfterArrayElement,
+            Some(Frame::Object) => Pending::AfterObjectValue,
+            None => Pending::CheckTrailing,
+        }
+    }
+
+    // Mirrors `Parser::parse_value_borrowed`: advances onto the first char of the
+    // value, then dispatches on it.
+    fn begin_value(&mut self) -> Result<JsonEvent> {
+        self.parser.advance();
+        self.parser.eat_whitespace();
+
+        let event = match self.parser.current_char {
+            Some('[') => JsonEvent::ArrayStart,
+            Some('{') => JsonEvent::ObjectStart,
+            Some('"') => JsonEvent::StringVal(self.parser.parse_string()?),
+            Some('n') => {
+                self.parser.parse_expected_value("ull", Json::Null)?;
+                JsonEvent::NullVal
             }
-            self.buffer.push(c);
-            self.advance();
+            Some('f') => {
+                self.parser
+                    .parse_expected_value("alse", Json::Boolean(false))?;
+                JsonEvent::BoolVal(false)
+            }
+            Some('t') => {
+                self.parser
+                    .parse_expected_value("rue", Json::Boolean(true))?;
+                JsonEvent::BoolVal(true)
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' => match self.parser.parse_digits()? {
+                Json::Integer(i) => JsonEvent::NumberVal(JsonNumber::Integer(i)),
+                Json::Number(n) => JsonEvent::NumberVal(JsonNumber::Float(n)),
+                _ => unreachable!("parse_digits only ever returns Integer or Number"),
+            },
+            Some(c) => return Err(anyhow!("Unexpected: {} in json value", c)),
+            None => return Err(anyhow!("Invalid json format.")),
+        };
+
+        match event {
+            JsonEvent::ArrayStart => {
+                self.stack.push(Frame::Array);
+                self.path.push(StackElement::Index(0));
+                self.pending = Pending::ValueExpected;
+            }
+            JsonEvent::ObjectStart => {
+                // No key has been read yet, so don't push a path element
+                // here: `begin_object_key` pushes the real one once it has
+                // a key in hand, keeping `path` free of placeholder keys
+                // that never existed in the document.
+                self.stack.push(Frame::Object);
+                self.pending = Pending::KeyExpected;
+            }
+            _ => self.pending = self.pending_after_value(),
         }
 
-        if seen_dot {
-            let value = self.buffer.parse()?;
-            // incredibly scuffed
-            if let Some(c) = self.current_char {
-                if c == '.' {
-                    return Err(anyhow!("invalid json number structure"));
+        Ok(event)
+    }
+
+    // Mirrors the key/colon handling at the top of `Parser::parse_object_borrowed`'s loop.
+    fn begin_object_key(&mut self) -> Result<JsonEvent> {
+        self.parser.advance();
+        self.parser.eat_whitespace();
+
+        match self.parser.current_char {
+            Some('"') => {}
+            Some(_) => return Err(anyhow!("Invalid json object composition, no separator")),
+            None => return Err(anyhow!("Invalid Json object composition")),
+        }
+
+        let key = self.parser.parse_string()?;
+
+        self.parser.advance();
+        self.parser.eat_whitespace();
+
+        match self.parser.current_char {
+            Some(':') => {}
+            Some(_) => return Err(anyhow!("Invalid json object composition, no separator")),
+            None => return Err(anyhow!("Invalid Json object composition")),
+        }
+
+        // The current object's frame has a path entry only once a key has
+        // been read; the first key of a frame pushes one, later keys (after
+        // a comma) update the one already there.
+        if self.path.len() < self.stack.len() {
+            self.path.push(StackElement::Key(key.clone()));
+        } else {
+            *self.path.last_mut().unwrap() = StackElement::Key(key.clone());
+        }
+        self.pending = Pending::ValueExpected;
+        Ok(JsonEvent::Key(key))
+    }
+
+    // Mirrors the comma/closing-bracket handling in `Parser::parse_array_borrowed`.
+    fn after_array_element(&mut self) -> Result<JsonEvent> {
+        self.parser.advance();
+        self.parser.eat_whitespace();
+
+        match self.parser.current_char {
+            Some(']') => {
+                self.stack.pop();
+                self.path.pop();
+                self.pending = self.pending_after_value();
+                Ok(JsonEvent::ArrayEnd)
+            }
+            Some(',') => {
+                if let Some(StackElement::Index(i)) = self.path.last_mut() {
+                    *i += 1;
                 }
+                self.begin_value()
             }
-            Ok(Json::Number(value))
-        } else {
-            let value = self.buffer.parse()?;
-            Ok(Json::Integer(value))
+            Some(c) => Err(anyhow!("Invalid json array structure, got: {c}")),
+            None => Err(anyhow!("Invalid json array structure, no closing ]")),
         }
     }
 
-    fn parse_value(&mut self) -> Result<Json> {
-        self.advance();
-        self.eat_whitespace();
+    // Mirrors the comma/closing-brace handling in `Parser::parse_object_borrowed`.
+    fn after_object_value(&mut self) -> Result<JsonEvent> {
+        self.parser.advance();
+        self.parser.eat_whitespace();
 
-        match self.current_char {
-            Some('[') => self.parse_array(),
-            Some('{') => self.parse_object(),
-            Some('"') => {
-                let value = self.parse_string()?;
-                Ok(Json::String(value))
-            }
-            Some('n') => self.parse_expected_value("ull", Json::Null),
-            Some('f') => self.parse_expected_value("alse", Json::Boolean(false)),
-            Some('t') => self.parse_expected_value("rue", Json::Boolean(true)),
-            Some(c) if c.is_ascii_digit() => self.parse_digits(),
-            Some(c) => Err(anyhow!("Unexpected: {} in json value", c)),
-            None => Err(anyhow!("Invalid json format.")),
+        match self.parser.current_char {
+            Some('}') => {
+                self.stack.pop();
+                self.path.pop();
+                self.pending = self.pending_after_value();
+                Ok(JsonEvent::ObjectEnd)
+            }
+            Some(',') => self.begin_object_key(),
+            Some(c) => Err(anyhow!("Invalid Json object composition, got: {}", c)),
+            None => Err(anyhow!("Invalid Json object composition, no closing }}")),
         }
     }
 
-    pub fn parse(&mut self) -> Result<Json> {
-        let result = self.parse_value()?;
-        self.advance();
-        self.eat_whitespace();
-        match self.current_char {
+    // Mirrors the trailing-content check at the end of `Parser::parse_borrowed`.
+    fn check_trailing(&mut self) -> Result<()> {
+        self.pending = Pending::Done;
+        self.parser.advance();
+        self.parser.eat_whitespace();
+        match self.parser.current_char {
             Some(_) => Err(anyhow!("Invalid json format.")),
-            None => Ok(result),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'p, 'a> Iterator for StreamingParser<'p, 'a> {
+    type Item = Result<(JsonEvent, Vec<StackElement>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let event = match self.pending {
+            Pending::Done => return None,
+            Pending::ValueExpected => self.begin_value(),
+            Pending::KeyExpected => self.begin_object_key(),
+            Pending::AfterArrayElement => self.after_array_element(),
+            Pending::AfterObjectValue => self.after_object_value(),
+            Pending::CheckTrailing => {
+                return match self.check_trailing() {
+                    Ok(()) => None,
+                    Err(e) => Some(Err(e)),
+                };
+            }
+        };
+
+        match event {
+            Ok(event) => Some(Ok((event, self.path.clone()))),
+            Err(e) => {
+                self.pending = Pending::Done;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl Json {
+    // Folds an event stream into a `Json` tree; this is what `Parser::parse`
+    // delegates to so both entry points share one code path.
+    fn from_events<'p, 'a>(events: &mut StreamingParser<'p, 'a>) -> Result<Json> {
+        let (event, _) = events
+            .next()
+            .ok_or_else(|| anyhow!("Invalid json format."))??;
+        let result = Json::from_event(event, events)?;
+
+        // Pull once more so the streaming parser's own trailing-content
+        // check (the same one `Parser::parse` used to run inline) still runs.
+        if let Some(trailing) = events.next() {
+            trailing?;
+        }
+
+        Ok(result)
+    }
+
+    fn from_event<'p, 'a>(
+        event: JsonEvent,
+        events: &mut StreamingParser<'p, 'a>,
+    ) -> Result<Json> {
+        match event {
+            JsonEvent::NullVal => Ok(Json::Null),
+            JsonEvent::BoolVal(b) => Ok(Json::Boolean(b)),
+            JsonEvent::StringVal(s) => Ok(Json::String(s)),
+            JsonEvent::NumberVal(JsonNumber::Integer(i)) => Ok(Json::Integer(i)),
+            JsonEvent::NumberVal(JsonNumber::Float(f)) => Ok(Json::Number(f)),
+            JsonEvent::ArrayStart => {
+                let mut values = Vec::new();
+                loop {
+                    let (next_event, _) = events
+                        .next()
+                        .ok_or_else(|| anyhow!("Invalid json array structure, no closing ]"))??;
+                    if next_event == JsonEvent::ArrayEnd {
+                        break;
+                    }
+                    values.push(Json::from_event(next_event, events)?);
+                }
+                Ok(Json::Array(values))
+            }
+            JsonEvent::ObjectStart => {
+                let mut map = HashMap::new();
+                loop {
+                    let (next_event, _) = events
+                        .next()
+                        .ok_or_else(|| anyhow!("Invalid Json object composition"))??;
+                    let key = match next_event {
+                        JsonEvent::ObjectEnd => break,
+                        JsonEvent::Key(key) => key,
+                        _ => return Err(anyhow!("expected a json object key")),
+                    };
+                    let (value_event, _) = events
+                        .next()
+                        .ok_or_else(|| anyhow!("Invalid Json object composition"))??;
+                    let value = Json::from_event(value_event, events)?;
+                    map.insert(key, value);
+                }
+                Ok(Json::Object(map))
+            }
+            JsonEvent::Key(_) | JsonEvent::ObjectEnd | JsonEvent::ArrayEnd => {
+                Err(anyhow!("Unexpected json event"))
+            }
+        }
+    }
+}
+
+fn mismatched_type(expected: &str, value: &Json) -> anyhow::Error {
+    anyhow!("expected {}, got {}", expected, value.type_name())
+}
+
+/// Type-directed decoding out of a `Json` tree, so callers don't have to
+/// match on the enum by hand for every field they pull out.
+trait FromJson: Sized {
+    fn from_json(value: &Json) -> Result<Self>;
+}
+
+impl FromJson for bool {
+    fn from_json(value: &Json) -> Result<Self> {
+        match value {
+            Json::Boolean(b) => Ok(*b),
+            _ => Err(mismatched_type("boolean", value)),
+        }
+    }
+}
+
+impl FromJson for f64 {
+    fn from_json(value: &Json) -> Result<Self> {
+        value.as_f64().ok_or_else(|| mismatched_type("number", value))
+    }
+}
+
+impl FromJson for i64 {
+    fn from_json(value: &Json) -> Result<Self> {
+        match value {
+            Json::Integer(i) => Ok(*i),
+            _ => Err(mismatched_type("integer", value)),
+        }
+    }
+}
+
+impl FromJson for usize {
+    fn from_json(value: &Json) -> Result<Self> {
+        match value {
+            Json::Integer(i) => {
+                usize::try_from(*i).map_err(|_| anyhow!("integer {} does not fit in usize", i))
+            }
+            _ => Err(mismatched_type("integer", value)),
+        }
+    }
+}
+
+impl FromJson for String {
+    fn from_json(value: &Json) -> Result<Self> {
+        value
+            .as_str()
+            .map(String::from)
+            .ok_or_else(|| mismatched_type("string", value))
+    }
+}
+
+impl<T: FromJson> FromJson for Option<T> {
+    fn from_json(value: &Json) -> Result<Self> {
+        match value {
+            Json::Null => Ok(None),
+            other => Ok(Some(T::from_json(other)?)),
+        }
+    }
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json(value: &Json) -> Result<Self> {
+        let values = value
+            .as_array()
+            .ok_or_else(|| mismatched_type("array", value))?;
+
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, item)| T::from_json(item).with_context(|| format!("at index {}", i)))
+            .collect()
+    }
+}
+
+impl<T: FromJson> FromJson for HashMap<String, T> {
+    fn from_json(value: &Json) -> Result<Self> {
+        match value {
+            Json::Object(map) => map
+                .iter()
+                .map(|(key, item)| {
+                    T::from_json(item)
+                        .map(|value| (key.clone(), value))
+                        .with_context(|| format!("at key \"{}\"", key))
+                })
+                .collect(),
+            _ => Err(mismatched_type("object", value)),
         }
     }
 }
@@ -262,6 +1073,52 @@ mod tests {
         assert_eq!(value, Json::Array(arr));
     }
 
+    #[test]
+    fn test_string_escapes() {
+        let json_value = r#""line1\nline2\ttab\"quote\\backslash""#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse().unwrap();
+
+        assert_eq!(
+            value,
+            Json::String("line1\nline2\ttab\"quote\\backslash".into())
+        );
+    }
+
+    #[test]
+    fn test_unicode_escape() {
+        let json_value = r#""caf\u00e9""#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse().unwrap();
+
+        assert_eq!(value, Json::String("café".into()));
+    }
+
+    #[test]
+    fn test_surrogate_pair_escape() {
+        let json_value = r#""\ud83d\ude00""#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse().unwrap();
+
+        assert_eq!(value, Json::String("😀".into()));
+    }
+
+    #[test]
+    fn test_unpaired_surrogate_is_error() {
+        let json_value = r#""\ud83d""#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse();
+        assert!(value.is_err());
+    }
+
+    #[test]
+    fn test_control_char_in_string_is_error() {
+        let json_value = "\"abc\ndef\"";
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse();
+        assert!(value.is_err());
+    }
+
     #[test]
     fn invalid_object() {
         let json_value = r#"{"#;
@@ -270,6 +1127,307 @@ mod tests {
         assert!(value.is_err());
     }
 
+    #[test]
+    fn test_negative_integer() {
+        let json_value = r#"-5"#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse().unwrap();
+        assert_eq!(value, Json::Integer(-5));
+    }
+
+    #[test]
+    fn test_negative_zero() {
+        let json_value = r#"-0"#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse().unwrap();
+        assert_eq!(value, Json::Integer(0));
+    }
+
+    #[test]
+    fn test_exponent_number() {
+        let json_value = r#"1e10"#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse().unwrap();
+        assert_eq!(value, Json::Number(1e10));
+    }
+
+    #[test]
+    fn test_negative_fraction_with_negative_exponent() {
+        let json_value = r#"-1.5E-3"#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse().unwrap();
+        assert_eq!(value, Json::Number(-1.5E-3));
+    }
+
+    #[test]
+    fn test_fraction_number() {
+        let json_value = r#"0.5"#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse().unwrap();
+        assert_eq!(value, Json::Number(0.5));
+    }
+
+    #[test]
+    fn test_leading_zero_is_error() {
+        let json_value = r#"01"#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse();
+        assert!(value.is_err());
+    }
+
+    #[test]
+    fn test_trailing_dot_is_error() {
+        let json_value = r#"1."#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse();
+        assert!(value.is_err());
+    }
+
+    #[test]
+    fn test_missing_exponent_digits_is_error() {
+        let json_value = r#"1e"#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse();
+        assert!(value.is_err());
+    }
+
+    #[test]
+    fn test_to_string_compact() {
+        let mut object = HashMap::new();
+        object.insert(String::from("b"), Json::Integer(2));
+        object.insert(String::from("a"), Json::Boolean(true));
+
+        let value = Json::Array(vec![
+            Json::Object(object),
+            Json::String("a\nb\"c".into()),
+            Json::Null,
+        ]);
+
+        assert_eq!(
+            value.to_string(),
+            r#"[{"a":true,"b":2},"a\nb\"c",null]"#
+        );
+    }
+
+    #[test]
+    fn test_to_pretty_string() {
+        let mut object = HashMap::new();
+        object.insert(String::from("b"), Json::Integer(2));
+        object.insert(String::from("a"), Json::Integer(1));
+
+        let value = Json::Object(object);
+
+        assert_eq!(value.to_pretty_string(2), "{\n  \"a\": 1,\n  \"b\": 2\n}");
+    }
+
+    #[test]
+    fn test_integral_number_round_trips_as_number() {
+        let value = Json::Number(2.0);
+        let encoded = value.to_string();
+        assert_eq!(encoded, "2.0");
+
+        let mut reparsed = Parser::new(&encoded);
+        assert_eq!(reparsed.parse().unwrap(), value);
+    }
+
+    #[test]
+    fn test_round_trip_through_parse_and_encode() {
+        let json_value = r#"{"a": [1, 2.5, "x\n"], "b": null}"#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse().unwrap();
+
+        let encoded = value.to_string();
+        let mut reparsed = Parser::new(&encoded);
+        assert_eq!(reparsed.parse().unwrap(), value);
+    }
+
+    #[test]
+    fn test_streaming_events_nested() {
+        let json_value = r#"{"a": [1, "two"], "b": false}"#;
+        let mut parser = Parser::new(json_value);
+        let events: Vec<JsonEvent> = parser
+            .events()
+            .map(|r| r.unwrap().0)
+            .collect();
+
+        assert_eq!(
+            events,
+            vec![
+                JsonEvent::ObjectStart,
+                JsonEvent::Key("a".into()),
+                JsonEvent::ArrayStart,
+                JsonEvent::NumberVal(JsonNumber::Integer(1)),
+                JsonEvent::StringVal("two".into()),
+                JsonEvent::ArrayEnd,
+                JsonEvent::Key("b".into()),
+                JsonEvent::BoolVal(false),
+                JsonEvent::ObjectEnd,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_streaming_events_track_path() {
+        let json_value = r#"[10, 20]"#;
+        let mut parser = Parser::new(json_value);
+        let paths: Vec<Vec<StackElement>> = parser
+            .events()
+            .map(|r| r.unwrap().1)
+            .collect();
+
+        assert_eq!(
+            paths,
+            vec![
+                vec![StackElement::Index(0)],
+                vec![StackElement::Index(0)],
+                vec![StackElement::Index(1)],
+                vec![],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_streaming_events_track_path_through_object() {
+        let json_value = r#"{"a": 1}"#;
+        let mut parser = Parser::new(json_value);
+        let events: Vec<(JsonEvent, Vec<StackElement>)> =
+            parser.events().map(|r| r.unwrap()).collect();
+
+        assert_eq!(
+            events,
+            vec![
+                (JsonEvent::ObjectStart, vec![]),
+                (JsonEvent::Key("a".into()), vec![StackElement::Key("a".into())]),
+                (
+                    JsonEvent::NumberVal(JsonNumber::Integer(1)),
+                    vec![StackElement::Key("a".into())]
+                ),
+                (JsonEvent::ObjectEnd, vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_streaming_parse_matches_tree_parse() {
+        let json_value = r#"{"nested": {"object": 1}, "list": [1, 2.5, "x"]}"#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse().unwrap();
+
+        let mut inner_object = HashMap::new();
+        inner_object.insert(String::from("object"), Json::Integer(1));
+
+        let mut expected = HashMap::new();
+        expected.insert(String::from("nested"), Json::Object(inner_object));
+        expected.insert(
+            String::from("list"),
+            Json::Array(vec![
+                Json::Integer(1),
+                Json::Number(2.5),
+                Json::String("x".into()),
+            ]),
+        );
+
+        assert_eq!(value, Json::Object(expected));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_parse_borrowed_without_escapes_slices_input() {
+        let json_value = r#"{"name": "abc", "list": [1, "xyz"]}"#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse_borrowed().unwrap();
+
+        let JsonRef::Object(map) = value else {
+            panic!("expected object");
+        };
+
+        let Some(JsonRef::Str(name)) = map.get("name") else {
+            panic!("expected name string");
+        };
+        assert!(matches!(name, Cow::Borrowed(_)));
+        assert_eq!(name.as_ref(), "abc");
+
+        let Some(JsonRef::Array(list)) = map.get("list") else {
+            panic!("expected list array");
+        };
+        assert_eq!(list, &vec![JsonRef::Integer(1), JsonRef::Str("xyz".into())]);
+    }
+
+    #[test]
+    fn test_parse_borrowed_with_escapes_falls_back_to_owned() {
+        let json_value = r#""a\nb""#;
+        let mut parser = Parser::new(json_value);
+        let value = parser.parse_borrowed().unwrap();
+
+        let JsonRef::Str(s) = value else {
+            panic!("expected string");
+        };
+        assert!(matches!(s, Cow::Owned(_)));
+        assert_eq!(s.as_ref(), "a\nb");
+    }
+
+    #[test]
+    fn test_from_json_scalars() {
+        assert!(bool::from_json(&Json::Boolean(true)).unwrap());
+        assert_eq!(f64::from_json(&Json::Number(1.5)).unwrap(), 1.5);
+        assert_eq!(f64::from_json(&Json::Integer(2)).unwrap(), 2.0);
+        assert_eq!(i64::from_json(&Json::Integer(-3)).unwrap(), -3);
+        assert_eq!(usize::from_json(&Json::Integer(3)).unwrap(), 3);
+        assert_eq!(
+            String::from_json(&Json::String("hi".into())).unwrap(),
+            "hi"
+        );
+    }
+
+    #[test]
+    fn test_from_json_mismatched_type_error_reports_both_variants() {
+        let err = bool::from_json(&Json::Integer(1)).unwrap_err();
+        assert_eq!(err.to_string(), "expected boolean, got integer");
+    }
+
+    #[test]
+    fn test_from_json_option() {
+        assert_eq!(Option::<i64>::from_json(&Json::Null).unwrap(), None);
+        assert_eq!(Option::<i64>::from_json(&Json::Integer(5)).unwrap(), Some(5));
+    }
+
+    #[test]
+    fn test_from_json_vec_reports_index_in_error_path() {
+        let array = Json::Array(vec![Json::Integer(1), Json::Boolean(true)]);
+        let err = Vec::<i64>::from_json(&array).unwrap_err();
+        assert_eq!(err.to_string(), "at index 1");
+        assert_eq!(err.root_cause().to_string(), "expected integer, got boolean");
+    }
+
+    #[test]
+    fn test_from_json_hashmap() {
+        let mut object = HashMap::new();
+        object.insert(String::from("a"), Json::Integer(1));
+        object.insert(String::from("b"), Json::Integer(2));
+
+        let decoded = HashMap::<String, i64>::from_json(&Json::Object(object)).unwrap();
+
+        let mut expected = HashMap::new();
+        expected.insert(String::from("a"), 1i64);
+        expected.insert(String::from("b"), 2i64);
+        assert_eq!(decoded, expected);
+    }
+
+    #[test]
+    fn test_json_accessors() {
+        let mut object = HashMap::new();
+        object.insert(String::from("name"), Json::String("abc".into()));
+        let value = Json::Object(object);
+
+        assert_eq!(value.get("name").and_then(Json::as_str), Some("abc"));
+        assert_eq!(value.get("missing"), None);
+        assert_eq!(Json::Integer(5).as_f64(), Some(5.0));
+        assert_eq!(
+            Json::Array(vec![Json::Null]).as_array(),
+            Some(&vec![Json::Null])
+        );
+    }
+
     #[test]
     fn verify_that_top_level_fails_if_extra_stuff_is_there() {
         let json_value = r#"1234 aihykuajnlsd"#;
@@ -285,4 +1443,31 @@ fn main() {
     let value = parser.parse();
 
     println!("{:?}", value);
+
+    if let Ok(value) = &value {
+        println!("{}", value);
+        println!("{}", value.to_pretty_string(2));
+    }
+
+    let mut streaming_parser = Parser::new(r#"{"nested": {"object": 1}}"#);
+    for event in streaming_parser.events() {
+        match event {
+            Ok((event, path)) => println!("{:?} at {:?}", event, path),
+            Err(e) => println!("streaming error: {}", e),
+        }
+    }
+
+    let json_value = r#"{"name": "abc", "list": [1, "xyz"]}"#;
+    let mut borrowing_parser = Parser::new(json_value);
+    println!("{:?}", borrowing_parser.parse_borrowed());
+
+    let json_value = r#"{"name": "abc", "scores": [1, 2, 3]}"#;
+    let mut parser = Parser::new(json_value);
+    if let Ok(value) = parser.parse() {
+        let name = value.get("name").and_then(Json::as_str);
+        println!("name: {:?}", name);
+
+        let scores = value.get("scores").map(Vec::<i64>::from_json);
+        println!("scores: {:?}", scores);
+    }
 }